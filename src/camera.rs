@@ -0,0 +1,84 @@
+use geng::prelude::*;
+
+pub struct Camera {
+    pub fov: f32,
+    pub rot: f32,
+    pub earth_radius: f32,
+    pub latitude: f32,
+    pub kick: f32,
+    ui_fov: f32,
+}
+
+impl Camera {
+    pub fn new(fov: f32, ui_fov: f32, rot: f32, earth_radius: f32) -> Self {
+        Self {
+            fov,
+            rot,
+            earth_radius,
+            latitude: 0.0,
+            kick: 0.0,
+            ui_fov,
+        }
+    }
+
+    pub fn fov(&self) -> f32 {
+        self.ui_fov
+    }
+
+    pub fn as_2d(&self) -> geng::Camera2d {
+        geng::Camera2d {
+            center: vec2::ZERO,
+            rotation: Angle::ZERO,
+            fov: self.ui_fov,
+        }
+    }
+
+    fn eye(&self) -> vec3<f32> {
+        let circle_pos = vec2(self.earth_radius, 0.0).rotate(self.latitude);
+        vec3(0.0, circle_pos.x, -circle_pos.y)
+    }
+
+    pub(crate) fn basis(&self) -> (vec3<f32>, vec3<f32>, vec3<f32>) {
+        let down = -self.eye().normalize_or_zero();
+        let tangent = vec3(0.0, -down.z, down.y);
+        let tilt = self.rot + self.kick;
+        let forward = (down * tilt.cos() + tangent * tilt.sin()).normalize_or_zero();
+        let right = vec3(1.0, 0.0, 0.0);
+        let up = vec3::cross(right, forward);
+        (forward, right, up)
+    }
+
+    pub(crate) fn view_matrix(&self) -> mat4<f32> {
+        let (forward, right, up) = self.basis();
+        let rotation = mat4::new([
+            [right.x, right.y, right.z, 0.0],
+            [up.x, up.y, up.z, 0.0],
+            [-forward.x, -forward.y, -forward.z, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        rotation * mat4::translate(-self.eye())
+    }
+
+    pub(crate) fn projection_matrix(&self, framebuffer_size: vec2<f32>) -> mat4<f32> {
+        let aspect = framebuffer_size.x / framebuffer_size.y;
+        mat4::perspective(self.fov + self.kick, aspect, 0.1, self.earth_radius * 4.0)
+    }
+
+    // Projects into the same fixed UI-space `as_2d()` draws in, independent
+    // of framebuffer size, so delivery detection stays deterministic across
+    // peers with different windows. `None` if the point is behind the camera.
+    pub fn project_to_ui(&self, world_pos: vec3<f32>) -> Option<vec2<f32>> {
+        let (forward, right, up) = self.basis();
+        let relative = world_pos - self.eye();
+        let depth = vec3::dot(relative, forward);
+        if depth <= 0.0 {
+            return None;
+        }
+        let half_fov = (self.fov / 2.0).clamp(0.01, f32::PI / 2.0 - 0.01);
+        let scale = self.ui_fov / 2.0 / (depth * half_fov.tan());
+        Some(vec2(
+            vec3::dot(relative, right) * scale,
+            vec3::dot(relative, up) * scale,
+        ))
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{Assets, Camera, Cubemap, Texture};
+use geng::prelude::*;
+
+#[derive(ugli::Vertex, Clone, Copy)]
+pub struct Vertex {
+    pub a_pos: vec3<f32>,
+    pub a_uv: vec2<f32>,
+}
+
+pub struct Draw3d {
+    assets: Rc<Assets>,
+    sprite_quad: ugli::VertexBuffer<Vertex>,
+    fullscreen_quad: ugli::VertexBuffer<Vertex>,
+}
+
+impl Draw3d {
+    pub fn new(geng: &Geng, assets: &Rc<Assets>) -> Self {
+        Self {
+            assets: assets.clone(),
+            sprite_quad: ugli::VertexBuffer::new_static(
+                geng.ugli(),
+                vec![
+                    Vertex {
+                        a_pos: vec3(-0.5, -0.5, 0.0),
+                        a_uv: vec2(0.0, 0.0),
+                    },
+                    Vertex {
+                        a_pos: vec3(0.5, -0.5, 0.0),
+                        a_uv: vec2(1.0, 0.0),
+                    },
+                    Vertex {
+                        a_pos: vec3(0.5, 0.5, 0.0),
+                        a_uv: vec2(1.0, 1.0),
+                    },
+                    Vertex {
+                        a_pos: vec3(-0.5, 0.5, 0.0),
+                        a_uv: vec2(0.0, 1.0),
+                    },
+                ],
+            ),
+            // Clip-space corners, drawn with no model/view transform - the
+            // skybox shader reconstructs a view direction per-pixel instead.
+            fullscreen_quad: ugli::VertexBuffer::new_static(
+                geng.ugli(),
+                vec![
+                    Vertex {
+                        a_pos: vec3(-1.0, -1.0, 0.0),
+                        a_uv: vec2(0.0, 0.0),
+                    },
+                    Vertex {
+                        a_pos: vec3(1.0, -1.0, 0.0),
+                        a_uv: vec2(1.0, 0.0),
+                    },
+                    Vertex {
+                        a_pos: vec3(1.0, 1.0, 0.0),
+                        a_uv: vec2(1.0, 1.0),
+                    },
+                    Vertex {
+                        a_pos: vec3(-1.0, 1.0, 0.0),
+                        a_uv: vec2(0.0, 1.0),
+                    },
+                ],
+            ),
+        }
+    }
+
+    fn camera_uniforms(&self, camera: &Camera, framebuffer_size: vec2<f32>) -> impl ugli::Uniforms {
+        ugli::uniforms! {
+            u_view_matrix: camera.view_matrix(),
+            u_projection_matrix: camera.projection_matrix(framebuffer_size),
+        }
+    }
+
+    pub fn draw(
+        &self,
+        framebuffer: &mut ugli::Framebuffer,
+        camera: &Camera,
+        mesh: &ugli::VertexBuffer<Vertex>,
+        mode: ugli::DrawMode,
+        texture: &Texture,
+    ) {
+        let framebuffer_size = framebuffer.size().map(|x| x as f32);
+        ugli::draw(
+            framebuffer,
+            &self.assets.shaders.mesh3d,
+            mode,
+            mesh,
+            (
+                ugli::uniforms! {
+                    u_model_matrix: mat4::identity(),
+                    u_texture: &**texture,
+                },
+                self.camera_uniforms(camera, framebuffer_size),
+            ),
+            ugli::DrawParameters {
+                depth_func: Some(ugli::DepthFunc::LessOrEqual),
+                ..Default::default()
+            },
+        );
+    }
+
+    pub fn draw_sprite(
+        &self,
+        framebuffer: &mut ugli::Framebuffer,
+        camera: &Camera,
+        texture: &Texture,
+        pos: vec3<f32>,
+        size: vec2<f32>,
+    ) {
+        let framebuffer_size = framebuffer.size().map(|x| x as f32);
+        let (_, right, up) = camera.basis();
+        let model_matrix = mat4::new([
+            [right.x * size.x, up.x * size.y, 0.0, pos.x],
+            [right.y * size.x, up.y * size.y, 0.0, pos.y],
+            [right.z * size.x, up.z * size.y, 0.0, pos.z],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        ugli::draw(
+            framebuffer,
+            &self.assets.shaders.sprite,
+            ugli::DrawMode::TriangleFan,
+            &self.sprite_quad,
+            (
+                ugli::uniforms! {
+                    u_model_matrix: model_matrix,
+                    u_texture: &**texture,
+                },
+                self.camera_uniforms(camera, framebuffer_size),
+            ),
+            ugli::DrawParameters {
+                depth_func: Some(ugli::DepthFunc::LessOrEqual),
+                blend_mode: Some(ugli::BlendMode::straight_alpha()),
+                ..Default::default()
+            },
+        );
+    }
+
+    // Depth writes off and depth test always-pass, so this has to be drawn
+    // first - before anything it should sit behind.
+    pub fn draw_skybox(
+        &self,
+        framebuffer: &mut ugli::Framebuffer,
+        camera: &Camera,
+        skybox: &Cubemap,
+    ) {
+        let framebuffer_size = framebuffer.size().map(|x| x as f32);
+        ugli::draw(
+            framebuffer,
+            &self.assets.shaders.skybox,
+            ugli::DrawMode::TriangleFan,
+            &self.fullscreen_quad,
+            (
+                ugli::uniforms! {
+                    u_pos_x: &*skybox.pos_x,
+                    u_neg_x: &*skybox.neg_x,
+                    u_pos_y: &*skybox.pos_y,
+                    u_neg_y: &*skybox.neg_y,
+                    u_pos_z: &*skybox.pos_z,
+                    u_neg_z: &*skybox.neg_z,
+                },
+                self.camera_uniforms(camera, framebuffer_size),
+            ),
+            ugli::DrawParameters {
+                depth_write: false,
+                depth_func: Some(ugli::DepthFunc::Always),
+                ..Default::default()
+            },
+        );
+    }
+}
@@ -2,9 +2,28 @@ use geng::prelude::*;
 
 mod camera;
 mod draw3d;
+mod rng;
+mod rollback;
 
 use camera::*;
 use draw3d::Draw3d;
+use rng::Rng;
+use rollback::Rollback;
+
+const FIXED_DT: f32 = 1.0 / 60.0;
+const PREDICTION_WINDOW: usize = 8;
+
+#[derive(Deserialize, Clone)]
+pub struct ParcelConfig {
+    pub name: String,
+    pub texture: std::path::PathBuf,
+    pub aspect: f32,
+    pub scale: f32,
+    pub mass: f32,
+    pub drag: f32,
+    pub spin: f32,
+    pub weight: f32,
+}
 
 #[derive(Deserialize)]
 pub struct Config {
@@ -12,7 +31,6 @@ pub struct Config {
     pub gravity: f32,
     pub throw_speed: f32,
     pub throw_angle: f32,
-    pub item_scale: f32,
     pub item_hold_scale: f32,
     pub hand_radius: f32,
     pub item_max_w: f32,
@@ -26,12 +44,33 @@ pub struct Config {
     pub road_width: f32,
     pub mailbox_size: f32,
     pub distance_between_mailboxes: f32,
+    pub master_volume: f64,
+    pub sfx_volume: f64,
+    pub music_volume: f64,
+    pub mailbox_ping_interval: f32,
+    pub mailbox_hearing_distance: f32,
+    // BTreeMap (not HashMap) so iteration order - and each parcel's `kind`
+    // index - is stable and deterministic across peers.
+    pub parcel: std::collections::BTreeMap<String, ParcelConfig>,
+    pub mailbox_mouth_radius: f32,
+    pub mailbox_request_chance: f32,
+    pub delivery_points: u32,
+    pub delivery_bonus_points: u32,
+    pub ground_y: f32,
+    pub wind_base: vec2<f32>,
+    pub wind_gust_strength: f32,
+    pub wind_gust_frequency: f32,
+    pub camera_smooth_time: f32,
+    pub camera_look_ahead_gain: f32,
+    pub camera_kick_strength: f32,
+    pub camera_kick_decay: f32,
 }
 
 #[derive(geng::asset::Load)]
 pub struct Shaders {
     pub sprite: ugli::Program,
     pub mesh3d: ugli::Program,
+    pub skybox: ugli::Program,
 }
 
 #[derive(Deref, DerefMut)]
@@ -57,47 +96,294 @@ impl geng::asset::Load for Texture {
     const DEFAULT_EXT: Option<&'static str> = ugli::Texture::DEFAULT_EXT;
 }
 
+#[derive(geng::asset::Load)]
+pub struct Cubemap {
+    pub pos_x: Texture,
+    pub neg_x: Texture,
+    pub pos_y: Texture,
+    pub neg_y: Texture,
+    pub pos_z: Texture,
+    pub neg_z: Texture,
+}
+
 #[derive(geng::asset::Load)]
 pub struct Assets {
     shaders: Shaders,
-    envelope: Rc<Texture>,
     bag: Texture,
     hand: Texture,
     holding_hand: Texture,
     mailbox: Texture,
     #[load(postprocess = "make_repeated")]
     road: Texture,
+    skybox: Option<Cubemap>,
+    #[load(postprocess = "make_looped")]
+    bgm: geng::Sound,
+    sfx_pickup: geng::Sound,
+    sfx_throw: geng::Sound,
+    sfx_delivery: geng::Sound,
+    mailbox_ping: geng::Sound,
+}
+
+fn make_looped(sound: &mut geng::Sound) {
+    sound.looped = true;
 }
 
 fn make_repeated(texture: &mut Texture) {
     texture.set_wrap_mode(ugli::WrapMode::Repeat);
 }
 
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct PlayerInput {
+    pub cursor: vec2<f32>,
+    pub press: bool,
+    pub release: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 struct Item {
-    texture: Rc<Texture>,
+    kind: usize,
     pos: vec2<f32>,
     vel: vec2<f32>,
     rot: f32,
     w: f32,
     half_size: vec2<f32>,
+    mass: f32,
+    drag: f32,
 }
 
 impl Item {
-    pub fn new(texture: &Rc<Texture>, scale: f32) -> Self {
+    pub fn new(kind: usize, half_size: vec2<f32>, mass: f32, drag: f32, rng: &mut Rng) -> Self {
         Self {
-            texture: texture.clone(),
+            kind,
             pos: vec2::ZERO,
             vel: vec2::ZERO,
-            rot: thread_rng().gen_range(0.0..2.0 * f32::PI),
+            rot: rng.gen_range(0.0..2.0 * f32::PI),
             w: 0.0,
-            half_size: vec2(texture.size().map(|x| x as f32).aspect(), 1.0) * scale,
+            half_size,
+            mass,
+            drag: drag * (half_size.x + half_size.y),
         }
     }
 }
 
+fn hand_hits_item(item: &Item, cursor: vec2<f32>, hand_radius: f32) -> bool {
+    Aabb2::ZERO.extend_uniform(1.0).contains(
+        (Quad::unit()
+            .scale(item.half_size.map(|x| x + hand_radius))
+            .rotate(item.rot)
+            .translate(item.pos)
+            .transform
+            .inverse()
+            * cursor.extend(1.0))
+        .into_2d(),
+    )
+}
+
+#[derive(Clone, Serialize, Deserialize)]
 struct Mailbox {
     pub x: f32,
     pub latitude: f32,
+    /// If set, delivering this specific parcel kind here scores bonus points.
+    pub requested_kind: Option<usize>,
+}
+
+fn mailbox_world_pos(mailbox: &Mailbox, config: &Config) -> vec3<f32> {
+    let circle_pos = vec2(config.earth_radius, 0.0).rotate(mailbox.latitude);
+    vec3(mailbox.x, circle_pos.x, -circle_pos.y)
+}
+
+fn pick_parcel_kind(config: &Config, rng: &mut Rng) -> usize {
+    let total_weight: f32 = config.parcel.values().map(|parcel| parcel.weight).sum();
+    let mut roll = rng.gen_range(0.0..total_weight.max(f32::MIN_POSITIVE));
+    for (index, parcel) in config.parcel.values().enumerate() {
+        if roll < parcel.weight {
+            return index;
+        }
+        roll -= parcel.weight;
+    }
+    config.parcel.len() - 1
+}
+
+fn parcel_config(config: &Config, kind: usize) -> &ParcelConfig {
+    config
+        .parcel
+        .values()
+        .nth(kind)
+        .expect("unknown parcel kind")
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct SimState {
+    rng: Rng,
+    items: Vec<Item>,
+    holding: Vec<Option<Item>>,
+    cursors: Vec<vec2<f32>>,
+    mailboxes: Vec<Mailbox>,
+    my_latitude: f32,
+    bag_position: Aabb2<f32>,
+    score: u32,
+    streak: u32,
+    delivered_count: u32,
+    wind_time: f32,
+    wind: vec2<f32>,
+}
+
+impl SimState {
+    fn new(players: usize, bag_position: Aabb2<f32>) -> Self {
+        Self {
+            rng: Rng::new(thread_rng().gen()),
+            items: vec![],
+            holding: vec![None; players],
+            cursors: vec![vec2::ZERO; players],
+            mailboxes: vec![],
+            my_latitude: 0.0,
+            bag_position,
+            score: 0,
+            streak: 0,
+            delivered_count: 0,
+            wind_time: 0.0,
+            wind: vec2::ZERO,
+        }
+    }
+}
+
+fn advance(sim: &mut SimState, inputs: &[PlayerInput], dt: f32, config: &Config) {
+    for (player, input) in inputs.iter().enumerate() {
+        sim.cursors[player] = input.cursor;
+
+        if input.press {
+            if let Some(index) = sim
+                .items
+                .iter()
+                .rposition(|item| hand_hits_item(item, input.cursor, config.hand_radius))
+            {
+                sim.holding[player] = Some(sim.items.remove(index));
+            } else if sim
+                .bag_position
+                .extend_uniform(config.hand_radius)
+                .contains(input.cursor)
+            {
+                let kind = pick_parcel_kind(config, &mut sim.rng);
+                let parcel = parcel_config(config, kind);
+                let half_size = vec2(parcel.aspect, 1.0) * parcel.scale;
+                sim.holding[player] = Some(Item::new(
+                    kind,
+                    half_size,
+                    parcel.mass,
+                    parcel.drag,
+                    &mut sim.rng,
+                ));
+            }
+        }
+
+        if input.release {
+            if let Some(mut item) = sim.holding[player].take() {
+                item.pos = input.cursor;
+                item.vel = (vec2(0.0, config.throw_target_height) - item.pos)
+                    .normalize_or_zero()
+                    .rotate(sim.rng.gen_range(
+                        -config.throw_angle.to_radians()..config.throw_angle.to_radians(),
+                    ))
+                    * config.throw_speed;
+                // Per-kind multiplier on the global spin cap.
+                let spin = parcel_config(config, item.kind).spin;
+                item.w = sim.rng.gen_range(-1.0..1.0) * config.item_max_w * spin;
+                sim.items.push(item);
+            }
+        }
+    }
+
+    // A gentle sinusoidal gust layered on top of a constant base wind, so
+    // conditions drift over the course of a ride instead of ever settling.
+    sim.wind_time += dt;
+    sim.wind = config.wind_base
+        + vec2(
+            (sim.wind_time * config.wind_gust_frequency).sin(),
+            (sim.wind_time * config.wind_gust_frequency * 1.3).cos(),
+        ) * config.wind_gust_strength;
+
+    for item in &mut sim.items {
+        item.vel.y -= config.gravity * dt;
+        // Quadratic drag against the air, not the ground: light/large
+        // parcels get pushed around by the wind, compact ones punch through.
+        let relative_vel = item.vel - sim.wind;
+        item.vel -= relative_vel * relative_vel.len() * item.drag / item.mass * dt;
+        item.pos += item.vel * dt;
+        item.rot += item.w * dt;
+    }
+
+    // Delivery detection: a falling item that overlaps a mailbox's mouth is
+    // delivered. Mailboxes live on the curved road in world space, so we
+    // project them into the same fixed UI-space items live in rather than
+    // comparing raw screen pixels, which would make delivery depend on the
+    // window size and break determinism across peers.
+    let mut projection_camera = Camera::new(
+        config.fov.to_radians(),
+        config.ui_fov,
+        config.camera_rot.to_radians(),
+        config.earth_radius + config.camera_height,
+    );
+    projection_camera.latitude = sim.my_latitude;
+    let mut delivered = vec![];
+    for (index, item) in sim.items.iter().enumerate() {
+        if item.vel.y >= 0.0 {
+            continue; // only count it while it's coming down
+        }
+        for mailbox in &sim.mailboxes {
+            let Some(ui_pos) = projection_camera.project_to_ui(mailbox_world_pos(mailbox, config))
+            else {
+                continue;
+            };
+            if (ui_pos - item.pos).len() < config.mailbox_mouth_radius {
+                delivered.push((index, mailbox.requested_kind));
+                break;
+            }
+        }
+    }
+    for (index, requested_kind) in delivered.into_iter().rev() {
+        let item = sim.items.remove(index);
+        sim.delivered_count += 1;
+        sim.streak += 1;
+        sim.score += if requested_kind == Some(item.kind) {
+            config.delivery_bonus_points
+        } else {
+            config.delivery_points
+        };
+    }
+
+    // A parcel that falls past the road without being delivered breaks the streak.
+    sim.items.retain(|item| {
+        if item.pos.y < config.ground_y {
+            sim.streak = 0;
+            false
+        } else {
+            true
+        }
+    });
+
+    sim.my_latitude += config.ride_speed * dt;
+
+    sim.mailboxes
+        .retain(|mailbox| mailbox.latitude > sim.my_latitude - f32::PI);
+    while sim
+        .mailboxes
+        .last()
+        .map_or(true, |mailbox| mailbox.latitude < sim.my_latitude + f32::PI)
+    {
+        let last_latitude = sim
+            .mailboxes
+            .last()
+            .map_or(sim.my_latitude, |mailbox| mailbox.latitude);
+        for x in [-1, 1] {
+            let requested_kind = (sim.rng.gen_range(0.0..1.0) < config.mailbox_request_chance)
+                .then(|| pick_parcel_kind(config, &mut sim.rng));
+            sim.mailboxes.push(Mailbox {
+                x: x as f32 * (config.road_width + config.mailbox_size / 2.0),
+                latitude: last_latitude + config.distance_between_mailboxes.to_radians(),
+                requested_kind,
+            });
+        }
+    }
 }
 
 struct Game {
@@ -106,35 +392,72 @@ struct Game {
     assets: Rc<Assets>,
     config: Rc<Config>,
     camera: Camera,
-    items: Vec<Item>,
-    bag_position: Aabb2<f32>,
-    holding: Option<Item>,
-    mailboxes: Vec<Mailbox>,
     draw3d: Draw3d,
-    my_latitude: f32,
     road_mesh: ugli::VertexBuffer<draw3d::Vertex>,
+    local_player: usize,
+    num_players: usize,
+    pending_press: bool,
+    pending_release: bool,
+    accumulator: f32,
+    rollback: Rollback<SimState, PlayerInput, Config>,
+    bgm_effect: geng::SoundEffect,
+    bgm_muted: bool,
+    mailbox_ping_timer: f32,
+    parcel_textures: Vec<Rc<Texture>>,
+    last_seen_delivered: u32,
+    camera_latitude_vel: f32,
+    camera_kick: f32,
+}
+
+// Critically-damped spring step - no overshoot, converges smoothly.
+fn spring_damp(current: &mut f32, velocity: &mut f32, target: f32, smooth_time: f32, dt: f32) {
+    let omega = 2.0 / smooth_time;
+    let x = omega * dt;
+    let exp = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = *current - target;
+    let temp = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * temp) * exp;
+    *current = target + (change + temp) * exp;
+}
+
+fn play_sound(sound: &geng::Sound, volume: f64) {
+    let mut effect = sound.effect();
+    effect.set_volume(volume);
+    effect.play();
+}
+
+fn play_sound_panned(sound: &geng::Sound, volume: f64, pan: f32) {
+    let mut effect = sound.effect();
+    effect.set_volume(volume);
+    effect.set_pan(pan);
+    effect.play();
 }
 
 impl Game {
-    pub fn new(geng: &Geng, assets: &Rc<Assets>, config: &Rc<Config>) -> Self {
+    pub fn new(
+        geng: &Geng,
+        assets: &Rc<Assets>,
+        config: &Rc<Config>,
+        parcel_textures: Vec<Rc<Texture>>,
+    ) -> Self {
         let camera = Camera::new(
             config.fov.to_radians(),
             config.ui_fov,
             config.camera_rot.to_radians(),
             config.earth_radius + config.camera_height,
         );
+        let bag_position = Aabb2::point(vec2(0.0, -camera.fov() / 2.0 + 1.0)).extend_uniform(1.0);
+        let sim = SimState::new(1, bag_position);
+        let mut bgm_effect = assets.bgm.effect();
+        bgm_effect.set_volume(config.master_volume * config.music_volume);
+        bgm_effect.play();
         Self {
             framebuffer_size: vec2::splat(1.0),
             geng: geng.clone(),
             assets: assets.clone(),
             config: config.clone(),
-            bag_position: Aabb2::point(vec2(0.0, -camera.fov() / 2.0 + 1.0)).extend_uniform(1.0),
             camera,
-            items: vec![],
-            holding: None,
-            mailboxes: vec![],
             draw3d: Draw3d::new(geng, assets),
-            my_latitude: 0.0,
             road_mesh: ugli::VertexBuffer::new_static(geng.ugli(), {
                 const N: usize = 100;
                 (0..=N)
@@ -150,8 +473,36 @@ impl Game {
                     })
                     .collect()
             }),
+            local_player: 0,
+            num_players: 1,
+            pending_press: false,
+            pending_release: false,
+            accumulator: 0.0,
+            rollback: Rollback::new(sim, FIXED_DT, PREDICTION_WINDOW, advance),
+            bgm_effect,
+            bgm_muted: false,
+            mailbox_ping_timer: 0.0,
+            parcel_textures,
+            last_seen_delivered: 0,
+            camera_latitude_vel: 0.0,
+            camera_kick: 0.0,
         }
     }
+
+    fn toggle_bgm_mute(&mut self) {
+        self.bgm_muted = !self.bgm_muted;
+        self.bgm_effect.set_volume(if self.bgm_muted {
+            0.0
+        } else {
+            self.config.master_volume * self.config.music_volume
+        });
+    }
+
+    // Not wired to a real transport yet - see Game::new's num_players/local_player.
+    #[allow(dead_code)]
+    pub fn submit_remote_input(&mut self, frame: u64, player: usize, input: PlayerInput) -> bool {
+        self.rollback.reconcile(frame, player, input, &self.config)
+    }
 }
 
 impl geng::State for Game {
@@ -161,87 +512,134 @@ impl geng::State for Game {
                 position,
                 button: geng::MouseButton::Left,
             } => {
-                let pos = self
+                self.pending_press = true;
+                // Predicted locally from the last rendered snapshot, purely
+                // for audio feedback - the authoritative grab happens inside
+                // `advance` once this tick is simulated.
+                let cursor = self
                     .camera
                     .as_2d()
                     .screen_to_world(self.framebuffer_size, position.map(|x| x as f32));
-                if let Some(index) = self.items.iter().rposition(|item| {
-                    Aabb2::ZERO.extend_uniform(1.0).contains(
-                        (Quad::unit()
-                            .scale(item.half_size.map(|x| x + self.config.hand_radius))
-                            .rotate(item.rot)
-                            .translate(item.pos)
-                            .transform
-                            .inverse()
-                            * pos.extend(1.0))
-                        .into_2d(),
-                    )
-                }) {
-                    self.holding = Some(self.items.remove(index));
-                } else if self
-                    .bag_position
-                    .extend_uniform(self.config.hand_radius)
-                    .contains(pos)
-                {
-                    self.holding = Some(Item::new(&self.assets.envelope, self.config.item_scale));
+                let sim = self.rollback.current(&self.config);
+                let grabbing = sim
+                    .items
+                    .iter()
+                    .any(|item| hand_hits_item(item, cursor, self.config.hand_radius))
+                    || sim
+                        .bag_position
+                        .extend_uniform(self.config.hand_radius)
+                        .contains(cursor);
+                if grabbing {
+                    play_sound(
+                        &self.assets.sfx_pickup,
+                        self.config.master_volume * self.config.sfx_volume,
+                    );
                 }
             }
             geng::Event::MouseUp {
-                position,
                 button: geng::MouseButton::Left,
+                ..
             } => {
-                let pos = self
-                    .camera
-                    .as_2d()
-                    .screen_to_world(self.framebuffer_size, position.map(|x| x as f32));
-                if let Some(mut item) = self.holding.take() {
-                    item.pos = pos;
-                    item.vel = (vec2(0.0, self.config.throw_target_height) - item.pos)
-                        .normalize_or_zero()
-                        .rotate(thread_rng().gen_range(
-                            -self.config.throw_angle.to_radians()
-                                ..self.config.throw_angle.to_radians(),
-                        ))
-                        * self.config.throw_speed;
-                    item.w = thread_rng().gen_range(-1.0..1.0) * self.config.item_max_w;
-                    self.items.push(item);
+                self.pending_release = true;
+                if self.rollback.current(&self.config).holding[self.local_player].is_some() {
+                    play_sound(
+                        &self.assets.sfx_throw,
+                        self.config.master_volume * self.config.sfx_volume,
+                    );
                 }
             }
+            geng::Event::KeyDown { key: geng::Key::M } => {
+                self.toggle_bgm_mute();
+            }
             _ => {}
         }
     }
     fn update(&mut self, delta_time: f64) {
-        let delta_time = delta_time as f32;
+        self.accumulator += delta_time as f32;
+        while self.accumulator >= FIXED_DT {
+            self.accumulator -= FIXED_DT;
+            let cursor = self.camera.as_2d().screen_to_world(
+                self.framebuffer_size,
+                self.geng.window().cursor_position().map(|x| x as f32),
+            );
+            let local_input = PlayerInput {
+                cursor,
+                press: std::mem::take(&mut self.pending_press),
+                release: std::mem::take(&mut self.pending_release),
+            };
+            let mut inputs = vec![PlayerInput::default(); self.num_players];
+            inputs[self.local_player] = local_input;
+            self.rollback.tick(inputs, self.local_player, &self.config);
 
-        for item in &mut self.items {
-            item.vel.y -= self.config.gravity * delta_time;
-            item.pos += item.vel * delta_time;
-            item.rot += item.w * delta_time;
+            let delivered_count = self.rollback.current(&self.config).delivered_count;
+            if delivered_count > self.last_seen_delivered {
+                for _ in 0..delivered_count - self.last_seen_delivered {
+                    play_sound(
+                        &self.assets.sfx_delivery,
+                        self.config.master_volume * self.config.sfx_volume,
+                    );
+                    self.camera_kick += self.config.camera_kick_strength;
+                }
+                self.last_seen_delivered = delivered_count;
+            }
         }
 
-        self.my_latitude += self.config.ride_speed * delta_time;
+        // Spring-follow the ride instead of snapping straight to it, with a
+        // small look-ahead so the camera leans into the direction of travel.
+        let target_latitude = self.rollback.current(&self.config).my_latitude
+            + self.config.camera_look_ahead_gain * self.config.ride_speed;
+        spring_damp(
+            &mut self.camera.latitude,
+            &mut self.camera_latitude_vel,
+            target_latitude,
+            self.config.camera_smooth_time,
+            delta_time as f32,
+        );
+        self.camera_kick *= (-self.config.camera_kick_decay * delta_time as f32).exp();
+        self.camera.kick = self.camera_kick;
 
-        self.mailboxes
-            .retain(|mailbox| mailbox.latitude > self.my_latitude - f32::PI);
-        while self.mailboxes.last().map_or(true, |mailbox| {
-            mailbox.latitude < self.my_latitude + f32::PI
-        }) {
-            let last_latitude = self
+        // Ambient mailbox pings: purely cosmetic, so it runs on wall-clock
+        // time rather than the fixed simulation tick.
+        self.mailbox_ping_timer -= delta_time as f32;
+        if self.mailbox_ping_timer <= 0.0 {
+            self.mailbox_ping_timer = self.config.mailbox_ping_interval;
+            let sim = self.rollback.current(&self.config);
+            if let Some((mailbox, distance)) = sim
                 .mailboxes
-                .last()
-                .map_or(self.my_latitude, |mailbox| mailbox.latitude);
-            for x in [-1, 1] {
-                self.mailboxes.push(Mailbox {
-                    x: x as f32 * (self.config.road_width + self.config.mailbox_size / 2.0),
-                    latitude: last_latitude + self.config.distance_between_mailboxes.to_radians(),
-                });
+                .iter()
+                .map(|mailbox| {
+                    let distance =
+                        (mailbox.latitude - sim.my_latitude).abs() * self.config.earth_radius;
+                    (mailbox, distance)
+                })
+                .filter(|&(_, distance)| distance < self.config.mailbox_hearing_distance)
+                .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            {
+                let attenuation = 1.0 - distance / self.config.mailbox_hearing_distance;
+                // Pan toward whichever side of the road the mailbox is on,
+                // so the ping reads as a direction cue, not just a volume one.
+                let pan = (mailbox.x / (self.config.road_width + self.config.mailbox_size / 2.0))
+                    .clamp(-1.0, 1.0);
+                play_sound_panned(
+                    &self.assets.mailbox_ping,
+                    self.config.master_volume * self.config.sfx_volume * attenuation as f64,
+                    pan,
+                );
             }
         }
     }
     fn draw(&mut self, framebuffer: &mut ugli::Framebuffer) {
         self.framebuffer_size = framebuffer.size().map(|x| x as f32);
-        self.camera.latitude = self.my_latitude;
+        let sim = self.rollback.current(&self.config);
+        // `camera.latitude` itself is kept up to date by the spring-follow
+        // in `update`, not snapped here, so the ride doesn't feel rigid.
         ugli::clear(framebuffer, Some(self.config.sky_color), Some(1.0), None);
+        if let Some(skybox) = &self.assets.skybox {
+            // Drawn as the very first pass, depth writes off and depth test
+            // always-pass, so it sits behind everything else no matter how
+            // the camera is currently oriented over the curved road.
+            self.draw3d.draw_skybox(framebuffer, &self.camera, skybox);
+        }
         self.draw3d.draw(
             framebuffer,
             &self.camera,
@@ -250,18 +648,12 @@ impl geng::State for Game {
             &self.assets.road,
         );
 
-        let mouse_pos = self.camera.as_2d().screen_to_world(
-            self.framebuffer_size,
-            self.geng.window().cursor_position().map(|x| x as f32),
-        );
-
-        for mailbox in &self.mailboxes {
-            let circle_pos = vec2(self.config.earth_radius, 0.0).rotate(mailbox.latitude);
+        for mailbox in &sim.mailboxes {
             self.draw3d.draw_sprite(
                 framebuffer,
                 &self.camera,
                 &self.assets.mailbox,
-                vec3(mailbox.x, circle_pos.x, -circle_pos.y),
+                mailbox_world_pos(mailbox, &self.config),
                 vec2::splat(self.config.mailbox_size),
             );
         }
@@ -269,39 +661,68 @@ impl geng::State for Game {
         self.geng.draw2d().draw2d(
             framebuffer,
             self.camera.as_2d(),
-            &draw2d::TexturedQuad::new(self.bag_position, &self.assets.bag),
+            &draw2d::TexturedQuad::new(sim.bag_position, &self.assets.bag),
         );
-        if let Some(item) = &self.holding {
+        for (player, holding) in sim.holding.iter().enumerate() {
+            if let Some(item) = holding {
+                self.geng.draw2d().draw2d(
+                    framebuffer,
+                    self.camera.as_2d(),
+                    &draw2d::TexturedQuad::unit(&*self.parcel_textures[item.kind])
+                        .scale(item.half_size * self.config.item_hold_scale)
+                        .rotate(item.rot)
+                        .translate(sim.cursors[player]),
+                );
+            }
+        }
+        for item in &sim.items {
             self.geng.draw2d().draw2d(
                 framebuffer,
                 self.camera.as_2d(),
-                &draw2d::TexturedQuad::unit(&*item.texture)
-                    .scale(item.half_size * self.config.item_hold_scale)
+                &draw2d::TexturedQuad::unit(&*self.parcel_textures[item.kind])
+                    .scale(item.half_size)
                     .rotate(item.rot)
-                    .translate(mouse_pos),
+                    .translate(item.pos),
             );
         }
-        for item in &self.items {
+
+        for (player, cursor) in sim.cursors.iter().enumerate() {
             self.geng.draw2d().draw2d(
                 framebuffer,
                 self.camera.as_2d(),
-                &draw2d::TexturedQuad::unit(&*item.texture)
-                    .scale(item.half_size)
-                    .rotate(item.rot)
-                    .translate(item.pos),
+                &draw2d::TexturedQuad::unit(if sim.holding[player].is_some() {
+                    &self.assets.holding_hand
+                } else {
+                    &self.assets.hand
+                })
+                .scale_uniform(self.config.hand_radius)
+                .translate(*cursor),
             );
         }
 
         self.geng.draw2d().draw2d(
             framebuffer,
             self.camera.as_2d(),
-            &draw2d::TexturedQuad::unit(if self.holding.is_some() {
-                &self.assets.holding_hand
-            } else {
-                &self.assets.hand
-            })
-            .scale_uniform(self.config.hand_radius)
-            .translate(mouse_pos),
+            &draw2d::Text::unit(
+                self.geng.default_font().clone(),
+                format!("Score: {}   Streak: {}", sim.score, sim.streak),
+                Rgba::WHITE,
+            )
+            .scale_uniform(0.3)
+            .translate(vec2(0.0, self.camera.fov() / 2.0 - 0.5)),
+        );
+
+        // Lets the player read current conditions before they commit to a throw.
+        self.geng.draw2d().draw2d(
+            framebuffer,
+            self.camera.as_2d(),
+            &draw2d::Text::unit(
+                self.geng.default_font().clone(),
+                format!("Wind: {:.1}, {:.1}", sim.wind.x, sim.wind.y),
+                Rgba::WHITE,
+            )
+            .scale_uniform(0.3)
+            .translate(vec2(0.0, self.camera.fov() / 2.0 - 0.9)),
         );
     }
 }
@@ -318,6 +739,15 @@ fn main() {
             .await
             .unwrap();
         let config = Rc::new(config);
-        Game::new(&geng, &assets, &config)
+        let mut parcel_textures = Vec::with_capacity(config.parcel.len());
+        for parcel in config.parcel.values() {
+            let texture: Texture = geng
+                .asset_manager()
+                .load(run_dir().join("assets").join(&parcel.texture))
+                .await
+                .unwrap();
+            parcel_textures.push(Rc::new(texture));
+        }
+        Game::new(&geng, &assets, &config, parcel_textures)
     })
 }
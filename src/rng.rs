@@ -0,0 +1,28 @@
+use geng::prelude::*;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed ^ 0x9e3779b97f4a7c15,
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    pub fn gen_range(&mut self, range: std::ops::Range<f32>) -> f32 {
+        let t = (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32;
+        range.start + t * (range.end - range.start)
+    }
+}
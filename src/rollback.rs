@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+
+/// GGRS-style rollback buffer: `base` is the last confirmed state, `inputs`
+/// holds guesses for frames that haven't been confirmed yet.
+pub struct Rollback<S, I, C> {
+    base: S,
+    dt: f32,
+    max_window: usize,
+    confirmed_frame: u64,
+    inputs: VecDeque<Vec<I>>,
+    // Parallel to `inputs`: which player's input at that frame is known-good
+    // versus still a guess `reconcile` might correct.
+    known: VecDeque<Vec<bool>>,
+    advance: fn(&mut S, &[I], f32, &C),
+}
+
+impl<S: Clone, I: Clone, C> Rollback<S, I, C> {
+    pub fn new(initial: S, dt: f32, max_window: usize, advance: fn(&mut S, &[I], f32, &C)) -> Self {
+        Self {
+            base: initial,
+            dt,
+            max_window,
+            confirmed_frame: 0,
+            inputs: VecDeque::new(),
+            known: VecDeque::new(),
+            advance,
+        }
+    }
+
+    pub fn frame(&self) -> u64 {
+        self.confirmed_frame + self.inputs.len() as u64
+    }
+
+    pub fn current(&self, ctx: &C) -> S {
+        let mut state = self.base.clone();
+        for inputs in &self.inputs {
+            (self.advance)(&mut state, inputs, self.dt, ctx);
+        }
+        state
+    }
+
+    // Returns `true` if the buffer was over `max_window` and the oldest
+    // frame had to be force-folded even though it wasn't fully confirmed yet.
+    pub fn tick(&mut self, inputs: Vec<I>, local_player: usize, ctx: &C) -> bool {
+        let mut known = vec![false; inputs.len()];
+        if let Some(slot) = known.get_mut(local_player) {
+            *slot = true;
+        }
+        self.inputs.push_back(inputs);
+        self.known.push_back(known);
+        self.fold_confirmed_prefix(ctx);
+        if self.inputs.len() > self.max_window {
+            self.force_fold_oldest(ctx);
+            true
+        } else {
+            false
+        }
+    }
+
+    // Returns `false` if `frame` had already been force-folded out of the
+    // buffer - a desync, since the wrong guess is now baked into `base`.
+    pub fn reconcile(&mut self, frame: u64, player: usize, input: I, ctx: &C) -> bool {
+        if frame < self.confirmed_frame {
+            return false;
+        }
+        let index = (frame - self.confirmed_frame) as usize;
+        let (Some(input_slot), Some(known_slot)) = (
+            self.inputs.get_mut(index).and_then(|f| f.get_mut(player)),
+            self.known.get_mut(index).and_then(|f| f.get_mut(player)),
+        ) else {
+            return false;
+        };
+        *input_slot = input;
+        *known_slot = true;
+        self.fold_confirmed_prefix(ctx);
+        true
+    }
+
+    fn fold_confirmed_prefix(&mut self, ctx: &C) {
+        while self
+            .known
+            .front()
+            .is_some_and(|known| known.iter().all(|&known| known))
+        {
+            self.force_fold_oldest(ctx);
+        }
+    }
+
+    fn force_fold_oldest(&mut self, ctx: &C) {
+        if let Some(oldest) = self.inputs.pop_front() {
+            self.known.pop_front();
+            (self.advance)(&mut self.base, &oldest, self.dt, ctx);
+            self.confirmed_frame += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advance(state: &mut i64, inputs: &[i64], _dt: f32, _ctx: &()) {
+        *state += inputs.iter().sum::<i64>();
+    }
+
+    fn rollback(max_window: usize) -> Rollback<i64, i64, ()> {
+        Rollback::new(0, 1.0, max_window, advance)
+    }
+
+    #[test]
+    fn reconcile_before_window_folds_the_correction() {
+        let mut r = rollback(8);
+        // Frame 0: local player guesses 1, remote player not in yet (0).
+        r.tick(vec![1, 0], 0, &());
+        // The remote input for frame 0 turns out to be 5, well within the window.
+        assert!(r.reconcile(0, 1, 5, &()));
+        for frame in 1..8 {
+            r.tick(vec![1, 0], 0, &());
+            let _ = frame;
+        }
+        // Once folded, `current()` should reflect the corrected input (5),
+        // not the original guess (0).
+        assert_eq!(r.current(&()), 1 * 8 + 5);
+    }
+
+    #[test]
+    fn reconcile_after_window_is_too_late() {
+        let mut r = rollback(4);
+        r.tick(vec![1, 0], 0, &());
+        // Push enough ticks to force frame 0 out of the buffer before the
+        // remote input for it ever arrives.
+        for _ in 0..4 {
+            r.tick(vec![1, 0], 0, &());
+        }
+        // The correction for frame 0 is now too late: it was already folded
+        // into `base` with the wrong guess.
+        assert!(!r.reconcile(0, 1, 5, &()));
+        assert_eq!(r.current(&()), 1 * 5);
+    }
+
+    #[test]
+    fn fully_known_frames_fold_without_waiting_for_the_window() {
+        let mut r = rollback(100);
+        r.tick(vec![1, 1], 0, &()); // only the local player (0) is known here; the reconcile below confirms player 1
+        assert!(r.reconcile(0, 1, 1, &()));
+        // Frame 0 is now fully known even though the window (100) is nowhere
+        // near exceeded - it should already be folded into `base`.
+        assert_eq!(r.frame(), 1);
+        r.tick(vec![2, 2], 0, &());
+        assert_eq!(r.current(&()), 2 + 4);
+    }
+}